@@ -1,10 +1,129 @@
 use std::{
+    cell::Cell,
     fmt::{self, Display, Formatter},
     io::{self, BufRead, Write, stdout},
     str::FromStr,
-    collections::HashMap,
+    time::Instant,
 };
 
+/// `BOT_SEED` 환경변수가 없을 때 쓰는 기본 시드
+const DEFAULT_SEED: u64 = 0x9E3779B97F4A7C15;
+
+/// 한 턴에 탐색이 쓸 수 있는 시간 예산 (judge의 턴당 제한 시간보다 여유있게 짧게 잡는다)
+const TURN_BUDGET_MS: u64 = 900;
+/// 상대의 입찰 공격성 비율(입찰액/그룹 가치)이 단일 표본으로 얼마나 커질 수 있는지의 상한.
+/// 이 배수를 넘는 표본(예: YACHT 방해용 고정 입찰)이 예측 평균을 통째로 끌고 가지 않게 막는다.
+const MAX_AGGRESSIVENESS_RATIO: f64 = 3.0;
+
+/// 탐색에 남은 시간을 재는 벽시계 타이머
+struct Timer {
+    start: Instant,
+}
+
+impl Timer {
+    /// 지금 시각을 기준으로 타이머 시작
+    fn start() -> Self {
+        Timer { start: Instant::now() }
+    }
+
+    /// 타이머가 시작된 이후 흐른 시간 (ms)
+    fn elapsed_ms(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+}
+
+/// 재현 가능한 xoshiro256** 의사난수 생성기. 플레이아웃과 자가 대국 전반에서 쓰인다
+mod rng {
+    /// 시드 하나로 256비트 내부 상태를 채우기 위한 SplitMix64 (xoshiro 계열이 권장하는 시딩 방법)
+    fn splitmix64(state: &mut u64) -> u64 {
+        *state = state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// xoshiro256**: 256비트 상태와 rotate-xor-shift 재귀를 쓰는 의사난수 생성기.
+    /// xorshift64보다 상태가 넓어 주기가 훨씬 길고 통계적 품질도 좋으면서, 같은 시드에서는
+    /// 항상 같은 수열을 재현한다.
+    #[derive(Clone, Copy)]
+    pub struct Xoshiro256StarStar {
+        state: [u64; 4],
+    }
+
+    impl Xoshiro256StarStar {
+        /// 주어진 시드를 SplitMix64로 퍼뜨려 256비트 상태를 채워 초기화
+        pub fn new(seed: u64) -> Self {
+            let mut sm_state = if seed == 0 { 0x9E3779B97F4A7C15 } else { seed };
+            let state = [
+                splitmix64(&mut sm_state),
+                splitmix64(&mut sm_state),
+                splitmix64(&mut sm_state),
+                splitmix64(&mut sm_state),
+            ];
+            Xoshiro256StarStar { state }
+        }
+
+        /// xoshiro256** 한 스텝: rotate-xor-shift 재귀로 상태를 갱신하고, `**` 스크램블러로 출력값을 만든다
+        pub fn next(&mut self) -> u64 {
+            let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+            let t = self.state[1] << 17;
+            self.state[2] ^= self.state[0];
+            self.state[3] ^= self.state[1];
+            self.state[1] ^= self.state[2];
+            self.state[0] ^= self.state[3];
+            self.state[2] ^= t;
+            self.state[3] = self.state[3].rotate_left(45);
+
+            result
+        }
+
+        /// 0..m 범위의 정수 난수
+        pub fn rand(&mut self, m: u64) -> u64 {
+            self.next() % m
+        }
+
+        /// `[min, max]` 구간의 정수를 거부 샘플링으로 치우침 없이 뽑는다.
+        /// 시간 기반 `% range` 방식과 달리 구간 경계 쪽으로 쏠리지 않는다.
+        pub fn gen_range(&mut self, min: i32, max: i32) -> i32 {
+            let n = (max - min + 1) as u64;
+            let limit = u64::MAX - (u64::MAX % n);
+            loop {
+                let r = self.next();
+                if r < limit {
+                    return min + (r % n) as i32;
+                }
+            }
+        }
+
+        /// 고정된 점프 다항식으로 상태를 2^128 스텝 앞으로 건너뛴다 — 같은 시드에서
+        /// 갈라져 나온 독립된 스트림이 필요할 때(예: 플레이아웃을 스레드별로 나눌 때)
+        /// 각 스레드가 `jump()`를 한 번씩 호출해 서로 겹치지 않는 구간을 쓰도록 한다.
+        /// 현재는 롤아웃을 단일 스레드에서 순차 실행하므로 호출부가 없지만, xoshiro256**의
+        /// 표준 동반 연산이라 RNG 자체의 일부로 남겨둔다.
+        #[allow(dead_code)]
+        pub fn jump(&mut self) {
+            const JUMP: [u64; 4] =
+                [0x180ec6d33cfd0aba, 0xd5a61266f0c9392c, 0xa9582618e03fc9aa, 0x39abdc4529b1661c];
+
+            let mut next_state = [0u64; 4];
+            for &word in &JUMP {
+                for bit in 0..64 {
+                    if word & (1u64 << bit) != 0 {
+                        for (n, s) in next_state.iter_mut().zip(self.state.iter()) {
+                            *n ^= *s;
+                        }
+                    }
+                    self.next();
+                }
+            }
+            self.state = next_state;
+        }
+    }
+}
+use rng::Xoshiro256StarStar;
+
 /// 가능한 주사위 규칙들을 나타내는 enum
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -59,52 +178,47 @@ struct Game {
     opp_state: GameState,
     /// 현재 턴 번호 (1부터 시작)
     current_round: i32,
-    /// 상대방의 각 턴별 입찰 가격 저장 (턴 번호 -> 입찰 가격)
-    opponent_bids: HashMap<i32, i32>,
+    /// 상대방이 그룹을 가져갈 때마다 (그 그룹의 추정 가치, 입찰액) 쌍을 기록한 표본.
+    /// 입찰액 자체보다 "가치 대비 얼마나 공격적으로 베팅했는가"를 복원하는 데 쓴다.
+    opponent_bid_history: Vec<(f64, f64)>,
     /// 상대방이 YACHT를 완성했는지 여부
     opponent_yacht_completed: bool,
+    /// 재현 가능한 난수열을 위한 시드 기반 생성기 (내부 가변성으로 `&self` 메서드에서도 사용)
+    rng: Cell<Xoshiro256StarStar>,
 }
 
 impl Game {
-    /// 새로운 게임 인스턴스 생성
+    /// 새로운 게임 인스턴스 생성. `BOT_SEED` 환경변수가 설정되어 있으면 해당 시드를,
+    /// 없으면 `DEFAULT_SEED`를 사용해 난수 생성기를 초기화한다.
     fn new() -> Self {
+        let seed = std::env::var("BOT_SEED")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_SEED);
+
         Game {
             my_state: GameState::new(),
             opp_state: GameState::new(),
             current_round: 0,  // 0으로 시작하여 첫 번째 ROLL에서 1로 증가
-            opponent_bids: HashMap::new(),
+            opponent_bid_history: Vec::new(),
             opponent_yacht_completed: false,
+            rng: Cell::new(Xoshiro256StarStar::new(seed)),
         }
     }
-    
-    /// 주사위 그룹에서 가장 많이 중복된 숫자의 개수를 반환하는 함수
-    fn get_max_duplicate_count(&self, dice_group: &[i32]) -> i32 {
-        let mut counts = [0; 7];  // 1~6까지의 개수 (인덱스 0은 사용하지 않음)
-        
-        // 각 숫자의 개수 세기
-        for &dice in dice_group {
-            counts[dice as usize] += 1;
-        }
-        
-        // 가장 많이 중복된 숫자의 개수 반환
-        *counts.iter().skip(1).max().unwrap()
-    }
-    
-    /// 상대방의 입찰 가격을 저장하는 함수
-    fn save_opponent_bid(&mut self, round: i32, bid_amount: i32) {
-        self.opponent_bids.insert(round, bid_amount);
+
+    /// 상대방이 그룹을 가져갈 때의 (추정 가치, 입찰액) 표본을 기록하는 함수
+    fn save_opponent_bid(&mut self, group_value: f64, bid_amount: i32) {
+        self.opponent_bid_history.push((group_value, bid_amount as f64));
     }
-    
-    /// 간단한 랜덤 함수 (시드 없이 현재 시간 기반)
+
+    /// 시드 기반 생성기에서 뽑은 `[min, max]` 범위의 정수 난수
     fn random_between(&self, min: i32, max: i32) -> i32 {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        let time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos() as u64;
-        min + (time % (max - min + 1) as u64) as i32
+        let mut rng = self.rng.get();
+        let value = rng.gen_range(min, max);
+        self.rng.set(rng);
+        value
     }
-    
+
     /// 상대방이 YACHT를 완성했는지 확인하는 함수
     fn check_opponent_yacht_completion(&mut self) {
         // 상대방이 YACHT 규칙을 사용했는지 확인
@@ -157,170 +271,79 @@ impl Game {
         None
     }
     
-    /// 내가 보유한 주사위에서 가장 많이 중복된 숫자를 찾는 함수
-    fn get_my_max_duplicate_number(&self) -> Option<(i32, i32)> {
-        let my_dice = &self.my_state.dice;
-        if my_dice.is_empty() {
-            return None;
-        }
-        
-        let mut counts = [0; 7];  // 1~6까지의 개수 (인덱스 0은 사용하지 않음)
-        for &dice in my_dice {
-            counts[dice as usize] += 1;
-        }
-        
-        // 가장 많이 중복된 숫자와 그 개수 찾기
-        let mut max_count = 0;
-        let mut max_number = 0;
-        for i in 1..=6 {
-            if counts[i] > max_count {
-                max_count = counts[i];
-                max_number = i;
+    /// 아직 사용하지 않은 규칙 중, 주어진 주사위 5개로 가장 높은 점수를 내는 규칙을 찾는 함수
+    fn best_open_rule(&self, rule_score: &[Option<i32>; 12], dice: &[i32; 5]) -> Option<(usize, i32)> {
+        let mut best: Option<(usize, i32)> = None;
+        for (rule_index, slot) in rule_score.iter().enumerate() {
+            if slot.is_some() {
+                continue;
             }
-        }
-        
-        if max_count > 0 {
-            Some((max_number as i32, max_count as i32))
-        } else {
-            None
-        }
-    }
-    
-    /// 내가 보유한 주사위의 중복 패턴을 기반으로 그룹을 선택하는 함수
-    fn select_group_based_on_my_dice(&self, dice_a: &[i32], dice_b: &[i32]) -> char {
-        // 내가 보유한 주사위에서 가장 많이 중복된 숫자 찾기
-        if let Some((my_max_number, _my_max_count)) = self.get_my_max_duplicate_number() {
-            // A그룹과 B그룹에서 해당 숫자가 몇 개 있는지 확인
-            let count_a = dice_a.iter().filter(|&&d| d == my_max_number).count();
-            let count_b = dice_b.iter().filter(|&&d| d == my_max_number).count();
-            
-            // 해당 숫자가 더 많이 있는 그룹 선택
-            if count_a > count_b {
-                return 'A';
-            } else if count_b > count_a {
-                return 'B';
-            } else if count_a == count_b && count_a > 0 {
-                // 개수가 같다면 턴에 따라 다르게 처리
-                if self.current_round <= 8 {
-                    // 1~8턴: 큰 수를 가져오기
-                    let sum_a: i32 = dice_a.iter().sum();
-                    let sum_b: i32 = dice_b.iter().sum();
-                    return if sum_a > sum_b { 'A' } else { 'B' };
-                } else {
-                    // 9턴 이후: 남은 조합에 따라 다르게 처리
-                    return self.select_group_by_remaining_combinations(dice_a, dice_b);
-                }
+            let rule = DiceRule::from_usize(rule_index).expect("rule_score index is always a valid rule");
+            let score = GameState::calculate_score(&DicePut { rule, dice: *dice });
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((rule_index, score));
             }
         }
-        
-        // 기본값: 합이 높은 쪽 선택
-        let sum_a: i32 = dice_a.iter().sum();
-        let sum_b: i32 = dice_b.iter().sum();
-        if sum_a > sum_b { 'A' } else { 'B' }
+        best
     }
-    
-    /// 남은 조합에 따라 그룹을 선택하는 함수 (9턴 이후)
-    fn select_group_by_remaining_combinations(&self, dice_a: &[i32], dice_b: &[i32]) -> char {
-        // 사용하지 않은 규칙들 확인
-        let unused_rules: Vec<usize> = self.my_state.rule_score
-            .iter()
-            .enumerate()
-            .filter_map(|(i, &score)| if score.is_none() { Some(i) } else { None })
-            .collect();
-        
-        // 각 그룹에서 사용할 수 있는 조합 점수 계산
-        let score_a = self.calculate_potential_score(dice_a, &unused_rules);
-        let score_b = self.calculate_potential_score(dice_b, &unused_rules);
-        
-        if score_a > score_b { 'A' } else { 'B' }
+
+    /// 1~6 사이의 눈을 가진 주사위 5개를 무작위로 생성
+    fn random_dice_set(&self) -> [i32; 5] {
+        let mut dice = [0; 5];
+        for d in &mut dice {
+            *d = self.random_between(1, 6);
+        }
+        dice
     }
-    
-    /// 주사위 그룹에서 사용할 수 있는 잠재적 점수 계산
-    fn calculate_potential_score(&self, dice: &[i32], unused_rules: &[usize]) -> i32 {
-        let mut max_score = 0;
-        
-        for &rule_index in unused_rules {
-            if let Some(rule) = DiceRule::from_usize(rule_index) {
-                // 각 규칙에 대해 가능한 최고 점수 계산
-                let score = self.calculate_rule_potential_score(dice, rule);
-                max_score = max_score.max(score);
-            }
+
+    /// 주어진 규칙 사용 현황에서 시작해, (있다면) `first_dice`를 가장 좋은 남은 규칙에 먼저 배치하고
+    /// 이후 라운드는 `future_draws`에 미리 뽑아 둔 주사위를 순서대로 그리디하게 채워 넣어
+    /// 최종 총점을 추정하는 한 번의 플레이아웃. 같은 `future_draws`를 여러 후보 비교에 재사용하면
+    /// 비교 대상끼리 같은 미래를 겪게 되어(공통 난수) 평균 차이의 분산이 줄어든다.
+    fn rollout_final_score(
+        &self,
+        rule_score: &[Option<i32>; 12],
+        first_dice: Option<&[i32; 5]>,
+        future_draws: &[[i32; 5]],
+    ) -> i32 {
+        let mut rule_score = *rule_score;
+
+        if let Some((rule_index, score)) = first_dice.and_then(|dice| self.best_open_rule(&rule_score, dice)) {
+            rule_score[rule_index] = Some(score);
         }
-        
-        max_score
+
+        let mut draw_index = 0;
+        loop {
+            let dice = match future_draws.get(draw_index) {
+                Some(dice) => *dice,
+                None => self.random_dice_set(),
+            };
+            draw_index += 1;
+            let Some((rule_index, score)) = self.best_open_rule(&rule_score, &dice) else { break };
+            rule_score[rule_index] = Some(score);
+        }
+
+        GameState::score_from_rules(&rule_score)
     }
-    
-    /// 특정 규칙에 대한 잠재적 점수 계산
-    fn calculate_rule_potential_score(&self, dice: &[i32], rule: DiceRule) -> i32 {
-        match rule {
-            DiceRule::One => dice.iter().filter(|&&d| d == 1).sum::<i32>() * 1000,
-            DiceRule::Two => dice.iter().filter(|&&d| d == 2).sum::<i32>() * 1000,
-            DiceRule::Three => dice.iter().filter(|&&d| d == 3).sum::<i32>() * 1000,
-            DiceRule::Four => dice.iter().filter(|&&d| d == 4).sum::<i32>() * 1000,
-            DiceRule::Five => dice.iter().filter(|&&d| d == 5).sum::<i32>() * 1000,
-            DiceRule::Six => dice.iter().filter(|&&d| d == 6).sum::<i32>() * 1000,
-            DiceRule::Choice => dice.iter().sum::<i32>() * 1000,
-            DiceRule::FourOfAKind => {
-                let mut counts = [0; 7];
-                for &d in dice {
-                    counts[d as usize] += 1;
-                }
-                if counts.iter().skip(1).any(|&c| c >= 4) {
-                    dice.iter().sum::<i32>() * 1000
-                } else {
-                    0
-                }
-            }
-            DiceRule::FullHouse => {
-                let mut counts = [0; 7];
-                for &d in dice {
-                    counts[d as usize] += 1;
-                }
-                let has_pair = counts.iter().skip(1).any(|&c| c == 2 || c == 5);
-                let has_triple = counts.iter().skip(1).any(|&c| c == 3 || c == 5);
-                if has_pair && has_triple {
-                    dice.iter().sum::<i32>() * 1000
-                } else {
-                    0
-                }
-            }
-            DiceRule::SmallStraight => {
-                let mut has = [false; 7];
-                for &d in dice {
-                    has[d as usize] = true;
-                }
-                if (has[1] && has[2] && has[3] && has[4]) ||
-                   (has[2] && has[3] && has[4] && has[5]) ||
-                   (has[3] && has[4] && has[5] && has[6]) {
-                    15000
-                } else {
-                    0
-                }
-            }
-            DiceRule::LargeStraight => {
-                let mut has = [false; 7];
-                for &d in dice {
-                    has[d as usize] = true;
-                }
-                if (has[1] && has[2] && has[3] && has[4] && has[5]) ||
-                   (has[2] && has[3] && has[4] && has[5] && has[6]) {
-                    30000
-                } else {
-                    0
-                }
-            }
-            DiceRule::Yacht => {
-                let mut counts = [0; 7];
-                for &d in dice {
-                    counts[d as usize] += 1;
-                }
-                if counts.iter().skip(1).any(|&c| c == 5) {
-                    50000
-                } else {
-                    0
-                }
-            }
+
+    /// 규칙 사용 현황이 `rule_score`인 쪽이 `group`을 가져왔을 때 얻는 기대 점수 증가분(한계가치)을
+    /// 몬테카를로 플레이아웃으로 추정. `my_state`와 `opp_state` 양쪽에 그대로 재사용할 수 있다.
+    ///
+    /// 항상 정확히 `MAX_ROLLOUTS`번을 반복한다 — 실행 속도에 따라 반복 횟수가 줄어드는 시간
+    /// 게이트를 두지 않아야 같은 입력(같은 RNG 스트림)에서 항상 같은 값이 나온다.
+    fn estimate_marginal_value(&self, rule_score: &[Option<i32>; 12], group: &[i32; 5]) -> f64 {
+        const MAX_ROLLOUTS: i32 = 60;
+        let open_rules = rule_score.iter().filter(|slot| slot.is_none()).count();
+
+        let mut total = 0i64;
+        for _ in 0..MAX_ROLLOUTS {
+            // with/without 양쪽이 같은 미래 주사위열을 겪도록 미리 뽑아 공유한다
+            let future_draws: Vec<[i32; 5]> = (0..open_rules).map(|_| self.random_dice_set()).collect();
+            let with_group = self.rollout_final_score(rule_score, Some(group), &future_draws);
+            let without_group = self.rollout_final_score(rule_score, None, &future_draws);
+            total += (with_group - without_group) as i64;
         }
+        total as f64 / MAX_ROLLOUTS as f64
     }
     // ================================ [필수 구현] ================================
     // ============================================================================
@@ -332,193 +355,238 @@ impl Game {
         if let Some((group, amount)) = self.should_block_yacht(dice_a, dice_b) {
             return Bid { group, amount };
         }
-        
-        // 내가 보유한 주사위의 중복 패턴을 기반으로 그룹 선택
-        let group = self.select_group_based_on_my_dice(dice_a, dice_b);
-        
-        // 첫 번째 턴인 경우 중복된 숫자가 가장 많은 그룹 선택
-        if self.current_round == 1 {
-            // 각 그룹에서 가장 많이 중복된 숫자의 개수 계산
-            let max_count_a = self.get_max_duplicate_count(dice_a);
-            let max_count_b = self.get_max_duplicate_count(dice_b);
-            
-            // 중복이 더 많은 그룹 선택
-            let (_, max_count) = if max_count_a >= max_count_b {
-                ('A', max_count_a)
-            } else {
-                ('B', max_count_b)
-            };
-            
-            let amount = match max_count {
-                3 => 1001,  // 3개 중복시 1001 입찰
-                4 => 2001,  // 4개 중복시 2001 입찰
-                _ => 1,     // 그 외에는 1 입찰
-            };
-            
-            Bid { group, amount }
-        } else if self.current_round == 2 {
-            // 두 번째 턴: 상대방의 1번째 턴 입찰가격에 따라 결정
-            let first_round_bid = self.opponent_bids.get(&1).unwrap_or(&0);
-            let amount = if *first_round_bid > 1 { 101 } else { 0 };
-            
-            Bid { group, amount }
-        } else if self.current_round == 3 {
-            // 세 번째 턴: 상대방의 2번째 턴 입찰가격이 홀수/짝수에 따라 랜덤 배팅
-            let second_round_bid = self.opponent_bids.get(&2).unwrap_or(&0);
-            let amount = if *second_round_bid % 2 == 1 {
-                // 홀수면 2, 3 중 랜덤
-                self.random_between(2, 3)
-            } else {
-                // 짝수면 0, 1 중 랜덤
-                self.random_between(0, 1)
-            };
-            
-            Bid { group, amount }
+
+        let group_a: [i32; 5] = dice_a.try_into().expect("dice_a is always 5 dice");
+        let group_b: [i32; 5] = dice_b.try_into().expect("dice_b is always 5 dice");
+
+        // 롤아웃 평가기로 "이 그룹을 가져왔을 때 내 최종 점수가 얼마나 늘어나는가"를
+        // 추정해 더 가치 높은 그룹을 목표로 삼는다. 두 그룹 모두 estimate_marginal_value의
+        // 고정 MAX_ROLLOUTS를 그대로 쓰므로 같은 표본 수로 공정하게 비교된다.
+        let my_value_a = self.estimate_marginal_value(&self.my_state.rule_score, &group_a);
+        let my_value_b = self.estimate_marginal_value(&self.my_state.rule_score, &group_b);
+
+        let (target_value, other_value, group) = if my_value_a >= my_value_b {
+            (my_value_a, my_value_b, 'A')
         } else {
-            // 4번째 턴 이후: 상대방의 2번째 턴 입찰가격이 홀수/짝수에 따라 랜덤 배팅
-            let second_round_bid = self.opponent_bids.get(&2).unwrap_or(&0);
-            let amount = if *second_round_bid % 2 == 1 {
-                // 홀수면 2, 3 중 랜덤
-                self.random_between(2, 3)
-            } else {
-                // 짝수면 0, 1 중 랜덤
-                self.random_between(0, 1)
-            };
-            
-            Bid { group, amount }
+            (my_value_b, my_value_a, 'B')
+        };
+
+        // 상대의 "가치 대비 공격성" 비율을 과거 기록으로부터 추정하고, 이번에 경합하는
+        // 그룹의 가치(target_value)에 그 비율을 곱해 상대 입찰액의 분포를 예측한다.
+        // 그 분포 위에서 기대 순이익을 최대화하는 입찰가를 찾는 1-ply 미니맥스.
+        // 표본 하나가 극단적으로 튀어도(예: YACHT 방해용 5001 고정 입찰) 예측 평균이
+        // 터무니없이 커지지 않도록, 그룹 가치의 몇 배 이내로 눌러 담는다.
+        let (agg_mean, agg_std) = self.opponent_aggressiveness_stats();
+        let opp_mean = (agg_mean * target_value.max(0.0)).min(target_value.max(0.0) * MAX_AGGRESSIVENESS_RATIO);
+        let opp_std = (agg_std * target_value.max(0.0)).clamp(1.0, target_value.max(1.0) * MAX_AGGRESSIVENESS_RATIO);
+        let amount = self.optimal_bid_amount(target_value, other_value, opp_mean, opp_std);
+
+        Bid { group, amount }
+    }
+
+    /// 상대방이 그룹을 가져갈 때마다 "입찰액 / 그 그룹의 추정 가치" 비율(공격성)을 구해
+    /// 평균과 표준편차를 추정한다. 표본이 2개 미만이거나 가치 추정이 거의 0이면
+    /// 아직 상대의 성향을 알 수 없으므로 넓게 퍼진 기본 비율 분포로 대신한다.
+    /// YACHT 방해용 5001 고정 입찰처럼 가치 대비 비정상적으로 큰 표본이 평균을 통째로
+    /// 끌고 가지 않도록, 비율 자체를 `MAX_AGGRESSIVENESS_RATIO` 이내로 눌러 담는다.
+    fn opponent_aggressiveness_stats(&self) -> (f64, f64) {
+        const MIN_GROUP_VALUE: f64 = 1.0;
+        let ratios: Vec<f64> = self
+            .opponent_bid_history
+            .iter()
+            .filter(|&&(value, _)| value > MIN_GROUP_VALUE)
+            .map(|&(value, amount)| (amount / value).clamp(0.0, MAX_AGGRESSIVENESS_RATIO))
+            .collect();
+        if ratios.len() < 2 {
+            return (0.3, 0.3);
         }
+
+        let mean = ratios.iter().sum::<f64>() / ratios.len() as f64;
+        let variance = ratios.iter().map(|&r| (r - mean).powi(2)).sum::<f64>() / ratios.len() as f64;
+        (mean, variance.sqrt().max(0.05))
+    }
+
+    /// 상대의 입찰액이 `Normal(mean, std)`를 따른다고 가정하고, 내가 금액 `b`를 써서
+    /// 목표 그룹 경합에서 이길 확률(`b`보다 상대 입찰이 작을 확률)을 적분해 기대 순이익
+    /// `p_win * (target_value - b) + (1 - p_win) * (other_value + b)`을 최대화하는 `b`를 찾는다.
+    /// (이기면 `b`를 내고 목표 그룹을, 지면 낸 `b`를 돌려받고 나머지 그룹을 가져간다 — `GameState::bid`와 동일한 정산)
+    /// 목표 그룹의 가치보다 비싸게 사면 이겨도 손해이므로, 탐색 상한을 `target_value`로도 제한한다
+    /// (상대 모델이 잘못 추정되어 `p_win`이 왜곡되더라도 손해 볼 상한 자체를 막는 안전장치).
+    fn optimal_bid_amount(&self, target_value: f64, other_value: f64, mean: f64, std: f64) -> i32 {
+        const STEPS: i32 = 200;
+        const MAX_BID: f64 = 100_000.0;
+
+        let max_b = target_value.clamp(0.0, MAX_BID);
+        let mut best_amount = 0i32;
+        let mut best_net = f64::MIN;
+        for step in 0..=STEPS {
+            let b = max_b * step as f64 / STEPS as f64;
+            let p_win = normal_cdf(b, mean, std);
+            let net = p_win * (target_value - b) + (1.0 - p_win) * (other_value + b);
+            if net > best_net {
+                best_net = net;
+                best_amount = b as i32;
+            }
+        }
+        best_amount.clamp(0, MAX_BID as i32)
     }
     // ============================================================================
     /// 주어진 주사위에 대해 사용할 규칙과 주사위를 정하는 함수
     /// 사용할 규칙과 사용할 주사위의 목록을 pair로 묶어서 반환
     // ============================================================================
-    fn calculate_put(&self) -> DicePut {
+    fn calculate_put(&self, timer: &Timer) -> DicePut {
         // 사용하지 않은 규칙들 찾기
         let unused_rules: Vec<usize> = self.my_state.rule_score
             .iter()
             .enumerate()
             .filter_map(|(i, &score)| if score.is_none() { Some(i) } else { None })
             .collect();
-        
-        // 각 규칙에 대해 최적의 주사위 조합과 점수 계산
-        let mut best_rule = 0;
-        let mut best_dice = [0; 5];
-        let mut best_score = 0;
-        
-        for &rule_index in &unused_rules {
-            if let Some(rule) = DiceRule::from_usize(rule_index) {
-                let (dice, score) = self.find_best_dice_for_rule(rule);
-                if score > best_score {
-                    best_score = score;
-                    best_rule = rule_index;
-                    best_dice = dice;
+
+        // 보유한 주사위(항상 정확히 5개)로 만들 수 있는 5개짜리 조합 후보들 — 실전에서는
+        // 후보가 정확히 하나뿐이라 아래 탐색은 사실상 "이 5개를 어느 규칙에 쓸지" 비교가 된다
+        let candidates = self.generate_dice_combinations(&self.my_state.dice);
+
+        // 모든 (규칙, 주사위 조합) 쌍을 몬테카를로 플레이아웃으로 평가해 가치를 매긴다.
+        // 즉시 점수만 보는 탐욕적 선택과 달리, 남은 규칙들을 무작위로 채워 나간 최종
+        // 총점(63000점 상단 보너스 포함)의 평균으로 비교하므로 당장은 낮아 보여도
+        // 좋은 규칙을 아껴두는 선택을 자연스럽게 선호하게 된다. 각 쌍의 평가 자체는
+        // 고정 반복 횟수라 결정적이고, 후보가 많아질 미래를 대비해 턴 예산을 넘기면
+        // (judge 타임아웃 방지용 바깥쪽 안전장치) 지금까지 찾은 최선으로만 멈춘다.
+        let mut best: Option<(usize, [i32; 5], f64)> = None;
+        'search: for &rule_index in &unused_rules {
+            for &dice in &candidates {
+                let value = self.rollout_put_value(
+                    &self.my_state.rule_score,
+                    rule_index,
+                    &dice,
+                    self.my_state.bid_score,
+                );
+                if best.is_none_or(|(_, _, best_value)| value > best_value) {
+                    best = Some((rule_index, dice, value));
+                }
+                if timer.elapsed_ms() >= TURN_BUDGET_MS {
+                    break 'search;
                 }
             }
         }
-        
+
+        // judge 프로토콜은 매 라운드 GET으로 정확히 5개를 주고 그 직후 SCORE를 요구하므로
+        // (simulate_one_game도 동일하게 모델링한다), 이 시점의 my_state.dice는 항상 정확히
+        // 5개다 — generate_dice_combinations는 그래서 항상 최소 한 개의 후보를 내놓고,
+        // unused_rules도 SCORE가 불릴 때는 항상 비어있지 않으므로 best는 None일 수 없다.
+        let (rule_index, dice, _) = best.expect(
+            "calculate_put is only called with exactly 5 held dice and at least one open rule",
+        );
         DicePut {
-            rule: DiceRule::from_usize(best_rule).unwrap(),
-            dice: best_dice,
+            rule: DiceRule::from_usize(rule_index).expect("rule_index is always a valid rule"),
+            dice,
         }
     }
-    
-    /// 특정 규칙에 대해 최적의 주사위 조합을 찾는 함수
-    fn find_best_dice_for_rule(&self, rule: DiceRule) -> ([i32; 5], i32) {
-        let my_dice = &self.my_state.dice;
-        let mut best_dice = [0; 5];
-        let mut best_score = 0;
-        
-        // 모든 가능한 5개 주사위 조합을 시도
-        if my_dice.len() >= 5 {
-            // 간단한 방법: 가장 높은 점수를 주는 조합 찾기
-            let combinations = self.generate_dice_combinations(my_dice);
-            
-            for dice in combinations {
-                let score = self.calculate_rule_potential_score(&dice, rule);
-                if score > best_score {
-                    best_score = score;
-                    best_dice = dice;
-                }
+
+    /// `rule_index`에 `dice`를 배치했을 때의 기대 최종 점수를 몬테카를로 플레이아웃으로 추정.
+    /// 배치를 먼저 확정한 뒤 남은 규칙들을 무작위 주사위로 그리디하게 채워 나가 `get_total_score`
+    /// (63000점 상단 보너스 포함)를 구하고, 이를 `MAX_ROLLOUTS`번 반복해 평균을 낸다.
+    ///
+    /// 항상 정확히 `MAX_ROLLOUTS`번을 반복한다 — 실행 속도에 따라 반복 횟수가 줄어드는 시간
+    /// 게이트를 두지 않아야 같은 입력(같은 RNG 스트림)에서 항상 같은 값이 나온다.
+    fn rollout_put_value(
+        &self,
+        rule_score: &[Option<i32>; 12],
+        rule_index: usize,
+        dice: &[i32; 5],
+        bid_score: i32,
+    ) -> f64 {
+        const MAX_ROLLOUTS: i32 = 100;
+
+        let rule = DiceRule::from_usize(rule_index).expect("rule_index is always a valid rule");
+        let placed_score = GameState::calculate_score(&DicePut { rule, dice: *dice });
+
+        let mut total = 0i64;
+        for _ in 0..MAX_ROLLOUTS {
+            let mut rs = *rule_score;
+            rs[rule_index] = Some(placed_score);
+            while let Some((ri, score)) = self.best_open_rule(&rs, &self.random_dice_set()) {
+                rs[ri] = Some(score);
             }
+            total += (GameState::score_from_rules(&rs) + bid_score) as i64;
         }
-        
-        (best_dice, best_score)
+        total as f64 / MAX_ROLLOUTS as f64
     }
-    
-    /// 주사위 목록에서 가능한 5개 조합들을 생성하는 함수
+
+    /// 보유한 주사위 중 5개를 골라 만들 수 있는 모든 "값 조합"을 빠짐없이 나열하는 함수.
+    /// 인덱스 기준 부분집합(`C(k,5)`)을 전부 뽑은 뒤 값이 같은 것끼리 중복 제거하는 대신,
+    /// 숫자(1~6)별 개수를 먼저 센 다음 "몇 개씩 뽑을지"를 나열하므로 애초에 같은 값 조합이
+    /// 두 번 생기지 않는다.
+    ///
+    /// 실전에서는 `dice`가 매 SCORE 시점마다 정확히 5개(judge가 GET으로 준 한 그룹)라서
+    /// 이 함수는 사실상 그 5개로 만들 수 있는 유일한 조합 하나만 반환하고, `calculate_put`의
+    /// "규칙 × 조합" 탐색은 "이 5개를 어느 규칙에 쓸지"로 단순화된다. `dice.len() > 5`를
+    /// 대비한 일반화는 그 경우에도 그대로 옳게 동작하도록 남겨둔 것뿐이다.
     fn generate_dice_combinations(&self, dice: &[i32]) -> Vec<[i32; 5]> {
-        let mut combinations = Vec::new();
-        
         if dice.len() < 5 {
-            return combinations;
+            return Vec::new();
         }
-        
-        // 간단한 방법: 처음 5개, 마지막 5개, 그리고 중복이 많은 조합들
-        if dice.len() >= 5 {
-            // 처음 5개
-            let first_five: [i32; 5] = dice[..5].try_into().unwrap();
-            combinations.push(first_five);
-            
-            // 마지막 5개
-            if dice.len() > 5 {
-                let last_five: [i32; 5] = dice[dice.len()-5..].try_into().unwrap();
-                combinations.push(last_five);
-            }
-            
-            // 중복이 많은 조합들 찾기
-            let mut counts = [0; 7];
-            for &d in dice {
-                counts[d as usize] += 1;
-            }
-            
-            // 가장 많이 중복된 숫자들로 조합 만들기
-            for target_num in 1..=6 {
-                if counts[target_num as usize] >= 3 {
-                    let mut combination = [target_num; 5];
-                    let mut used = 0;
-                    
-                    // 해당 숫자들을 먼저 채우기
-                    for (i, &d) in dice.iter().enumerate() {
-                        if d == target_num && used < 5 {
-                            combination[used] = d;
-                            used += 1;
-                        }
-                    }
-                    
-                    // 나머지는 다른 숫자들로 채우기
-                    for (i, &d) in dice.iter().enumerate() {
-                        if d != target_num && used < 5 {
-                            combination[used] = d;
-                            used += 1;
-                        }
-                    }
-                    
-                    combinations.push(combination);
-                }
+
+        let mut counts = [0i32; 7];
+        for &d in dice {
+            counts[d as usize] += 1;
+        }
+
+        let mut combinations = Vec::new();
+        let mut picked = Vec::with_capacity(5);
+        Self::pick_faces(1, 5, &counts, &mut picked, &mut combinations);
+
+        // 값 조합이 정말로 한 번씩만 나오는지(문서에 적은 보장) 디버그 빌드에서 검증
+        debug_assert!(
+            combinations
+                .iter()
+                .enumerate()
+                .all(|(i, a)| combinations[i + 1..].iter().all(|b| a != b)),
+            "generate_dice_combinations produced a duplicate value-combination"
+        );
+
+        combinations
+    }
+
+    /// `face`번 숫자부터 시작해, 남은 `remaining`개를 채우는 모든 경우를 재귀로 나열
+    fn pick_faces(
+        face: i32,
+        remaining: i32,
+        counts: &[i32; 7],
+        picked: &mut Vec<i32>,
+        out: &mut Vec<[i32; 5]>,
+    ) {
+        if remaining == 0 {
+            out.push(picked.as_slice().try_into().expect("exactly 5 dice picked"));
+            return;
+        }
+        if face > 6 {
+            return;
+        }
+
+        let max_take = counts[face as usize].min(remaining);
+        for take in 0..=max_take {
+            for _ in 0..take {
+                picked.push(face);
             }
-            
-            // 연속된 숫자 조합들 (STRAIGHT용)
-            for start in 1..=2 {
-                let mut combination = [0; 5];
-                let mut used = 0;
-                
-                for num in start..start+5 {
-                    if num <= 6 {
-                        combination[used] = num;
-                        used += 1;
-                    }
-                }
-                
-                if used == 5 {
-                    combinations.push(combination);
-                }
+            Self::pick_faces(face + 1, remaining - take, counts, picked, out);
+            for _ in 0..take {
+                picked.pop();
             }
         }
-        
-        combinations
     }
+
     // ============================== [필수 구현 끝] ==============================
 
+    // NOTE(backlog #chunk2-2, #chunk0-5): 둘 다 "보유한 여러 라운드치 주사위 그룹을 담금질
+    // 기법으로 한꺼번에 재배치"하는 다중 그룹 솔버(`best_assignment`/`sa_accepts`/
+    // `endgame_annealing_put`)를 요구했다. 6a7deb8/02f0780에서 구현했으나 judge 프로토콜은
+    // 매 라운드 정확히 5개짜리 그룹 하나만 GET으로 건네고 그 직후 SCORE를 요구하므로
+    // (simulate_one_game 주석 참고) 보유 주사위가 5개를 넘는 경우가 존재하지 않아
+    // `num_groups`가 항상 1 — 다중 그룹 재배치라는 전제 자체가 이 프로토콜에는 적용되지
+    // 않는다. 2c66873에서 죽은 코드로 제거했다. 두 요청 모두 이 프로토콜에는 적용 불가능한
+    // 요청이며, 위의 단일 그룹 롤아웃 탐색(`calculate_put`)이 실질적인 대체 구현이다.
+
     /// 입찰 결과를 받아서 상태 업데이트
     fn update_get(
         &mut self,
@@ -528,9 +596,16 @@ impl Game {
         opp_bid: &Bid,       // 상대 입찰 정보
         my_group: char,      // 내가 가져간 그룹
     ) {
-        // 상대방의 입찰 가격 저장
-        self.save_opponent_bid(self.current_round, opp_bid.amount);
-        
+        let opp_group = if my_group == 'A' { 'B' } else { 'A' };  // 상대가 가져간 그룹
+
+        // 상대가 가져간 그룹이 상대에게 얼마나 가치 있었는지 추정해, 입찰액과 함께
+        // 공격성 표본으로 남긴다 (상대의 관점이므로 opp_state 기준으로 평가한다)
+        let opp_dice: [i32; 5] = if opp_group == 'A' { dice_a } else { dice_b }
+            .try_into()
+            .expect("dice group is always 5 dice");
+        let opp_group_value = self.estimate_marginal_value(&self.opp_state.rule_score, &opp_dice);
+        self.save_opponent_bid(opp_group_value, opp_bid.amount);
+
         // 그룹에 따라 주사위 분배
         if my_group == 'A' {
             self.my_state.add_dice(dice_a);   // 내가 A그룹 가져감
@@ -542,7 +617,6 @@ impl Game {
         // 입찰 결과에 따른 점수 반영
         let my_bid_ok = my_bid.group == my_group;  // 내 입찰 성공 여부
         self.my_state.bid(my_bid_ok, my_bid.amount);
-        let opp_group = if my_group == 'A' { 'B' } else { 'A' };  // 상대가 가져간 그룹
         let opp_bid_ok = opp_bid.group == opp_group;  // 상대 입찰 성공 여부
         self.opp_state.bid(opp_bid_ok, opp_bid.amount);
     }
@@ -570,12 +644,17 @@ impl GameState {
 
     /// 현재까지 획득한 총 점수 계산 (기본 점수 + 보너스 + 조합 점수 + 입찰 점수)
     fn get_total_score(&self) -> i32 {
+        Self::score_from_rules(&self.rule_score) + self.bid_score
+    }
+
+    /// 규칙 사용 현황만으로 점수 계산 (기본 점수 + 보너스 + 조합 점수, 입찰 점수는 제외)
+    fn score_from_rules(rule_score: &[Option<i32>; 12]) -> i32 {
         let mut basic = 0;      // 기본 점수 (ONE~SIX)
         let mut combination = 0; // 조합 점수 (CHOICE~YACHT)
         let mut bonus = 0;      // 보너스 점수
         // 기본 점수 규칙 계산 (ONE ~ SIX)
         for i in 0..6 {
-            if let Some(score) = self.rule_score[i] {
+            if let Some(score) = rule_score[i] {
                 basic += score;
             }
         }
@@ -585,11 +664,11 @@ impl GameState {
         }
         // 조합 점수 규칙 계산 (CHOICE ~ YACHT)
         for i in 6..12 {
-            if let Some(score) = self.rule_score[i] {
+            if let Some(score) = rule_score[i] {
                 combination += score;
             }
         }
-        basic + bonus + combination + self.bid_score
+        basic + bonus + combination
     }
 
     /// 입찰 결과에 따른 점수 반영
@@ -772,8 +851,362 @@ impl Display for DiceRule {
     }
 }
 
+/// 오차 함수(erf)의 Abramowitz-Stegun 근사 (최대 오차 약 1.5e-7)
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    const A1: f64 = 0.254829592;
+    const A2: f64 = -0.284496736;
+    const A3: f64 = 1.421413741;
+    const A4: f64 = -1.453152027;
+    const A5: f64 = 1.061405429;
+    const P: f64 = 0.3275911;
+
+    let t = 1.0 / (1.0 + P * x);
+    let poly = ((((A5 * t + A4) * t + A3) * t + A2) * t + A1) * t;
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
+/// 평균 `mean`, 표준편차 `std`인 정규분포에서 값이 `x` 이하일 확률 (누적분포함수)
+fn normal_cdf(x: f64, mean: f64, std: f64) -> f64 {
+    if std <= 0.0 {
+        return if x >= mean { 1.0 } else { 0.0 };
+    }
+    0.5 * (1.0 + erf((x - mean) / (std * std::f64::consts::SQRT_2)))
+}
+
+/// 자가 대국에서 교체해 가며 맞붙일 수 있는 입찰/배치 전략. 같은 `Game` 상태를 두고
+/// 서로 다른 의사결정 로직을 꽂아 넣어 새 아이디어를 기존 전략과 비교 측정할 수 있게 한다.
+trait Strategy {
+    /// 입찰할 그룹과 금액을 결정
+    fn bid(&self, game: &Game, dice_a: &[i32], dice_b: &[i32]) -> Bid;
+    /// 사용할 규칙과 주사위를 결정
+    fn put(&self, game: &Game) -> DicePut;
+}
+
+/// 이 파일의 [필수 구현] 구간에 담긴 현재 휴리스틱(롤아웃 기반 입찰/배치)을 그대로 감싼 전략
+struct HeuristicStrategy;
+
+impl Strategy for HeuristicStrategy {
+    fn bid(&self, game: &Game, dice_a: &[i32], dice_b: &[i32]) -> Bid {
+        game.calculate_bid(dice_a, dice_b)
+    }
+
+    fn put(&self, game: &Game) -> DicePut {
+        let timer = Timer::start();
+        game.calculate_put(&timer)
+    }
+}
+
+/// 주사위나 상대 정보를 전혀 보지 않고 무작위로만 결정하는 기준선 전략.
+/// 새 전략이 최소한 이것보다는 나은지 확인하는 용도
+struct RandomStrategy;
+
+impl Strategy for RandomStrategy {
+    fn bid(&self, game: &Game, _dice_a: &[i32], _dice_b: &[i32]) -> Bid {
+        let group = if game.random_between(0, 1) == 0 { 'A' } else { 'B' };
+        let amount = game.random_between(0, 1000);
+        Bid { group, amount }
+    }
+
+    fn put(&self, game: &Game) -> DicePut {
+        let unused_rules: Vec<usize> = game.my_state.rule_score
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &score)| if score.is_none() { Some(i) } else { None })
+            .collect();
+        let rule_index = unused_rules[game.random_between(0, unused_rules.len() as i32 - 1) as usize];
+        let dice: [i32; 5] = game.my_state.dice[..5].try_into().expect("at least 5 dice available to place");
+
+        DicePut {
+            rule: DiceRule::from_usize(rule_index).expect("rule_index is always a valid rule"),
+            dice,
+        }
+    }
+}
+
+/// 전략 이름(`"heuristic"` 또는 `"random"`)으로부터 전략 인스턴스를 생성. 모르는 이름이면
+/// 현재 휴리스틱으로 대체한다
+fn strategy_from_name(name: &str) -> Box<dyn Strategy> {
+    match name {
+        "random" => Box::new(RandomStrategy),
+        _ => Box::new(HeuristicStrategy),
+    }
+}
+
+/// 실제 judge 없이 두 `Game` 인스턴스를 맞붙여 한 판을 끝까지 진행하고 최종 점수를 반환.
+///
+/// 매 라운드 update_get으로 5개를 받은 직후 update_put으로 그 5개를 바로 소비하는 것은
+/// 단순화가 아니라 실제 judge 프로토콜 그대로다 — main()의 `Command::Get` 처리도 매번
+/// GET 직후 SCORE를 받아 그 라운드 안에서 곧바로 배치하며, 여러 GET을 쌓아뒀다가 한꺼번에
+/// SCORE하는 경로는 없다. 즉 보유 주사위는 실전에서도 항상 정확히 5개이고, 이 시뮬레이터의
+/// 승률/평균 점수는 실제 대전 양상을 그대로 반영한다.
+fn simulate_one_game(rng: &mut Xoshiro256StarStar, strategy_a: &dyn Strategy, strategy_b: &dyn Strategy) -> (i32, i32) {
+    let mut game_a = Game::new();
+    let mut game_b = Game::new();
+
+    for round in 1..=12 {
+        game_a.current_round = round;
+        game_b.current_round = round;
+
+        // ROLL: 양쪽 그룹 주사위를 무작위로 생성
+        let dice_a = random_dice_group(rng);
+        let dice_b = random_dice_group(rng);
+
+        let bid_a = strategy_a.bid(&game_a, &dice_a, &dice_b);
+        let bid_b = strategy_b.bid(&game_b, &dice_a, &dice_b);
+
+        // 입찰 경매 해소: 더 높은 금액을 부른 쪽이 자신이 고른 그룹을 가져가고,
+        // 동점이면 고른 그룹과 상관없이 game_a가 이긴 것으로 처리
+        let a_wins_auction = bid_a.amount >= bid_b.amount;
+        let winner_group = if a_wins_auction { bid_a.group } else { bid_b.group };
+        let loser_group = if winner_group == 'A' { 'B' } else { 'A' };
+        let (a_group, b_group) = if a_wins_auction {
+            (winner_group, loser_group)
+        } else {
+            (loser_group, winner_group)
+        };
+
+        game_a.update_get(&dice_a, &dice_b, &bid_a, &bid_b, a_group);
+        game_b.update_get(&dice_a, &dice_b, &bid_b, &bid_a, b_group);
+
+        // SCORE: 양쪽이 각자 배치를 결정하고 서로의 결과를 반영
+        let put_a = strategy_a.put(&game_a);
+        let put_b = strategy_b.put(&game_b);
+        game_a.update_put(&put_a);
+        game_b.update_put(&put_b);
+        game_a.update_set(&put_b);
+        game_b.update_set(&put_a);
+    }
+
+    (game_a.my_state.get_total_score(), game_b.my_state.get_total_score())
+}
+
+/// 1~6 사이의 눈을 가진 주사위 5개로 이루어진 그룹을 무작위로 생성
+fn random_dice_group(rng: &mut Xoshiro256StarStar) -> [i32; 5] {
+    let mut dice = [0; 5];
+    for d in &mut dice {
+        *d = rng.rand(6) as i32 + 1;
+    }
+    dice
+}
+
+/// `--simulate` 모드: N판을 시뮬레이션해 전략별 승률과 평균 점수를 집계해 출력
+fn run_simulation(num_games: i32, seed: u64, name_a: &str, name_b: &str) {
+    let strategy_a = strategy_from_name(name_a);
+    let strategy_b = strategy_from_name(name_b);
+
+    let mut rng = Xoshiro256StarStar::new(seed);
+    let mut a_wins = 0;
+    let mut b_wins = 0;
+    let mut ties = 0;
+    let mut a_score_sum = 0i64;
+    let mut b_score_sum = 0i64;
+
+    for _ in 0..num_games {
+        let (score_a, score_b) = simulate_one_game(&mut rng, strategy_a.as_ref(), strategy_b.as_ref());
+        a_score_sum += score_a as i64;
+        b_score_sum += score_b as i64;
+        match score_a.cmp(&score_b) {
+            std::cmp::Ordering::Greater => a_wins += 1,
+            std::cmp::Ordering::Less => b_wins += 1,
+            std::cmp::Ordering::Equal => ties += 1,
+        }
+    }
+
+    println!("games={num_games} seed={seed}");
+    println!(
+        "A({name_a}): win_rate={:.3} avg_score={:.1}",
+        a_wins as f64 / num_games as f64,
+        a_score_sum as f64 / num_games as f64,
+    );
+    println!(
+        "B({name_b}): win_rate={:.3} avg_score={:.1}",
+        b_wins as f64 / num_games as f64,
+        b_score_sum as f64 / num_games as f64,
+    );
+    println!("ties={ties}");
+}
+
+/// `--simulate` 모드의 `-n <games>`, `-s <seed>`, `--a <strategy>`, `--b <strategy>` 인자를
+/// 파싱 (없으면 기본값 사용: 100판, 시드 42, 양쪽 모두 `heuristic`)
+fn parse_simulate_args(args: &[String]) -> (i32, u64, String, String) {
+    let mut num_games = 100;
+    let mut seed = 42u64;
+    let mut name_a = "heuristic".to_string();
+    let mut name_b = "heuristic".to_string();
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-n" => {
+                if let Some(v) = args.get(i + 1).and_then(|s| s.parse().ok()) {
+                    num_games = v;
+                }
+                i += 1;
+            }
+            "-s" => {
+                if let Some(v) = args.get(i + 1).and_then(|s| s.parse().ok()) {
+                    seed = v;
+                }
+                i += 1;
+            }
+            "--a" => {
+                if let Some(v) = args.get(i + 1) {
+                    name_a = v.clone();
+                }
+                i += 1;
+            }
+            "--b" => {
+                if let Some(v) = args.get(i + 1) {
+                    name_b = v.clone();
+                }
+                i += 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    (num_games, seed, name_a, name_b)
+}
+
+/// 프로토콜 한 줄을 해석하는 중 발생한 오류. 어떤 명령의 어떤 필드를 읽다가 실패했는지를 담아
+/// stderr 로그만으로 원인을 짚을 수 있게 한다
+#[derive(Debug)]
+enum ParseError {
+    MissingToken { command: &'static str, field: &'static str },
+    InvalidDice { command: &'static str, field: &'static str, value: String },
+    InvalidGroup { command: &'static str, field: &'static str, value: String },
+    InvalidInt { command: &'static str, field: &'static str, value: String },
+    InvalidRule { command: &'static str, field: &'static str, value: String },
+    UnknownCommand(String),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingToken { command, field } => write!(f, "{command}: '{field}' 토큰이 없음"),
+            ParseError::InvalidDice { command, field, value } => {
+                write!(f, "{command}: '{field}' 주사위 문자열이 올바르지 않음 ({value:?})")
+            }
+            ParseError::InvalidGroup { command, field, value } => {
+                write!(f, "{command}: '{field}' 그룹 문자가 올바르지 않음 ({value:?})")
+            }
+            ParseError::InvalidInt { command, field, value } => {
+                write!(f, "{command}: '{field}' 정수값이 올바르지 않음 ({value:?})")
+            }
+            ParseError::InvalidRule { command, field, value } => {
+                write!(f, "{command}: '{field}' 규칙 이름이 올바르지 않음 ({value:?})")
+            }
+            ParseError::UnknownCommand(name) => write!(f, "알 수 없는 명령어: {name:?}"),
+        }
+    }
+}
+
+/// 한 프로토콜 명령을 구조화한 표현
+#[derive(Debug)]
+enum Command {
+    Ready,
+    Roll { dice_a: [i32; 5], dice_b: [i32; 5] },
+    Get { my_group: char, opp_group: char, opp_amount: i32 },
+    Score,
+    Set { rule: DiceRule, dice: [i32; 5] },
+    Finish,
+}
+
+/// 공백으로 나뉜 토큰들을 하나씩 소비하며 타입이 있는 값으로 읽어 들이는 토크나이저.
+/// 토큰이 모자라거나 형식이 틀리면 `panic!` 대신 `ParseError`를 돌려준다
+struct Tokens<'a> {
+    parts: std::str::SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(line: &'a str) -> Self {
+        Tokens { parts: line.split_whitespace() }
+    }
+
+    fn next_token(&mut self, command: &'static str, field: &'static str) -> Result<&'a str, ParseError> {
+        self.parts.next().ok_or(ParseError::MissingToken { command, field })
+    }
+
+    /// 다섯 자리 숫자 문자열(예: "12345")을 주사위 5개 배열로 읽는다
+    fn read_dice(&mut self, command: &'static str, field: &'static str) -> Result<[i32; 5], ParseError> {
+        let token = self.next_token(command, field)?;
+        let invalid = || ParseError::InvalidDice { command, field, value: token.to_string() };
+
+        if token.chars().count() != 5 {
+            return Err(invalid());
+        }
+        let mut dice = [0; 5];
+        for (dice_val, c) in dice.iter_mut().zip(token.chars()) {
+            let digit = c.to_digit(10).ok_or_else(invalid)? as i32;
+            if !(1..=6).contains(&digit) {
+                return Err(invalid());
+            }
+            *dice_val = digit;
+        }
+        Ok(dice)
+    }
+
+    /// `'A'` 또는 `'B'` 한 글자로 된 그룹 토큰을 읽는다
+    fn read_group(&mut self, command: &'static str, field: &'static str) -> Result<char, ParseError> {
+        let token = self.next_token(command, field)?;
+        let mut chars = token.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c @ ('A' | 'B')), None) => Ok(c),
+            _ => Err(ParseError::InvalidGroup { command, field, value: token.to_string() }),
+        }
+    }
+
+    fn read_i32(&mut self, command: &'static str, field: &'static str) -> Result<i32, ParseError> {
+        let token = self.next_token(command, field)?;
+        token.parse().map_err(|_| ParseError::InvalidInt { command, field, value: token.to_string() })
+    }
+
+    fn read_rule(&mut self, command: &'static str, field: &'static str) -> Result<DiceRule, ParseError> {
+        let token = self.next_token(command, field)?;
+        token.parse().map_err(|_| ParseError::InvalidRule { command, field, value: token.to_string() })
+    }
+}
+
+/// 표준 입력 한 줄을 구조화된 `Command`로 해석한다. 형식이 어긋나면 `ParseError`를 돌려주고,
+/// 호출부가 로그를 남긴 뒤 그 줄만 건너뛸 수 있게 한다
+fn parse_command(line: &str) -> Result<Command, ParseError> {
+    let mut tokens = Tokens::new(line);
+    let name = tokens.next_token("LINE", "command")?;
+    match name {
+        "READY" => Ok(Command::Ready),
+        "ROLL" => {
+            let dice_a = tokens.read_dice("ROLL", "dice_a")?;
+            let dice_b = tokens.read_dice("ROLL", "dice_b")?;
+            Ok(Command::Roll { dice_a, dice_b })
+        }
+        "GET" => {
+            let my_group = tokens.read_group("GET", "my_group")?;
+            let opp_group = tokens.read_group("GET", "opp_group")?;
+            let opp_amount = tokens.read_i32("GET", "opp_amount")?;
+            Ok(Command::Get { my_group, opp_group, opp_amount })
+        }
+        "SCORE" => Ok(Command::Score),
+        "SET" => {
+            let rule = tokens.read_rule("SET", "rule")?;
+            let dice = tokens.read_dice("SET", "dice")?;
+            Ok(Command::Set { rule, dice })
+        }
+        "FINISH" => Ok(Command::Finish),
+        other => Err(ParseError::UnknownCommand(other.to_string())),
+    }
+}
+
 /// 표준 입력을 통해 명령어를 처리하는 메인 함수
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|a| a == "--simulate") {
+        let (num_games, seed, name_a, name_b) = parse_simulate_args(&args);
+        run_simulation(num_games, seed, &name_a, &name_b);
+        return;
+    }
+
     let stdin = io::stdin();
     let mut game = Game::new();  // 새로운 게임 인스턴스 생성
 
@@ -785,33 +1218,37 @@ fn main() {
 
     // 표준 입력에서 한 줄씩 읽어서 명령어 처리
     for line in stdin.lock().lines() {
-        let line = line.unwrap();
+        // 읽기 자체가 실패하는 경우(예: 잘못된 UTF-8)도 패닉 대신 로그만 남기고 건너뛴다 —
+        // 아래 parse_command 실패 처리와 동일하게, 판 전체를 포기시키지 않는다
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                eprintln!("표준 입력 읽기 실패, 건너뜀: {err}");
+                continue;
+            }
+        };
         if line.trim().is_empty() {
             continue;  // 빈 줄은 무시
         }
 
-        let parts: Vec<_> = line.split_whitespace().collect();  // 공백으로 명령어 분리
-        let command = parts[0];  // 첫 번째 부분이 명령어
+        // 형식이 어긋난 줄은 봇 전체를 죽이는 대신 stderr에 남기고 건너뛴다
+        let command = match parse_command(&line) {
+            Ok(command) => command,
+            Err(err) => {
+                eprintln!("입력 줄 파싱 실패, 건너뜀: {err} (line: {line:?})");
+                continue;
+            }
+        };
+
         match command {
-            "READY" => {
+            Command::Ready => {
                 // 게임 시작 준비 완료
                 println!("OK");
                 stdout().flush().unwrap();
             }
-            "ROLL" => {
+            Command::Roll { dice_a: dice_a_array, dice_b: dice_b_array } => {
                 // 주사위 굴리기 결과 받기
                 game.current_round += 1;  // 턴 증가
-                let str_a = parts[1];  // A그룹 주사위 문자열
-                let str_b = parts[2];  // B그룹 주사위 문자열
-                let mut dice_a_array = [0; 5];  // A그룹 주사위 배열
-                let mut dice_b_array = [0; 5];  // B그룹 주사위 배열
-                // 문자열을 숫자 배열로 변환
-                for (dice_val, c) in dice_a_array.iter_mut().zip(str_a.chars()) {
-                    *dice_val = c.to_digit(10).unwrap() as i32;
-                }
-                for (dice_val, c) in dice_b_array.iter_mut().zip(str_b.chars()) {
-                    *dice_val = c.to_digit(10).unwrap() as i32;
-                }
                 dice_a = Some(dice_a_array);
                 dice_b = Some(dice_b_array);
                 // 입찰 계산 및 출력
@@ -820,27 +1257,19 @@ fn main() {
                 println!("BID {group} {amount}");
                 stdout().flush().unwrap();
             }
-            "GET" => {
-                // 주사위 받기 (입찰 결과)
-                let get_group = parts[1].chars().next().unwrap();  // 내가 가져간 그룹
-                let opp_group = parts[2].chars().next().unwrap();  // 상대가 가져간 그룹
-                let opp_score = parts[3].parse::<i32>().unwrap();  // 상대 입찰 점수
-                let my_bid_ref = my_bid.as_ref().unwrap();
-                // 게임 상태 업데이트
-                game.update_get(
-                    dice_a.as_ref().unwrap(),
-                    dice_b.as_ref().unwrap(),
-                    my_bid_ref,
-                    &Bid {
-                        group: opp_group,
-                        amount: opp_score,
-                    },
-                    get_group,
-                );
+            Command::Get { my_group, opp_group, opp_amount } => {
+                // 주사위 받기 (입찰 결과). ROLL/BID를 먼저 거치지 않고 GET이 온 경우는
+                // 프로토콜 순서가 어긋난 것이므로 패닉 대신 건너뛴다
+                let (Some(da), Some(db), Some(bid)) = (dice_a.as_ref(), dice_b.as_ref(), my_bid.as_ref()) else {
+                    eprintln!("ROLL/BID 없이 GET을 받아 건너뜀");
+                    continue;
+                };
+                game.update_get(da, db, bid, &Bid { group: opp_group, amount: opp_amount }, my_group);
             }
-            "SCORE" => {
-                // 주사위 골라서 배치하기 (점수 획득 단계)
-                let put = game.calculate_put();
+            Command::Score => {
+                // 주사위 골라서 배치하기 (점수 획득 단계, 이 시점부터 턴 예산을 잰다)
+                let timer = Timer::start();
+                let put = game.calculate_put(&timer);
                 game.update_put(&put);
                 // PUT 명령어 출력
                 print!("PUT {} ", put.rule);
@@ -850,24 +1279,110 @@ fn main() {
                 println!();
                 stdout().flush().unwrap();
             }
-            "SET" => {
+            Command::Set { rule, dice } => {
                 // 상대의 주사위 배치 결과 받기
-                let rule: DiceRule = parts[1].parse().unwrap();  // 상대가 사용한 규칙
-                let dice_vec: Vec<i32> = parts[2]  // 상대가 사용한 주사위
-                    .chars()
-                    .map(|c| c.to_digit(10).unwrap() as i32)
-                    .collect();
-                let dice: [i32; 5] = dice_vec.try_into().unwrap();
                 game.update_set(&DicePut { rule, dice });
             }
-            "FINISH" => {
+            Command::Finish => {
                 // 게임 종료
                 break;
             }
-            _ => {
-                // 알 수 없는 명령어 처리
-                panic!("Invalid command: {command}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod parser_tests {
+    use super::*;
+
+    #[test]
+    fn parses_ready() {
+        assert!(matches!(parse_command("READY").unwrap(), Command::Ready));
+    }
+
+    #[test]
+    fn parses_roll_with_valid_dice() {
+        let command = parse_command("ROLL 12345 66654").unwrap();
+        match command {
+            Command::Roll { dice_a, dice_b } => {
+                assert_eq!(dice_a, [1, 2, 3, 4, 5]);
+                assert_eq!(dice_b, [6, 6, 6, 5, 4]);
             }
+            _ => panic!("expected Command::Roll"),
         }
     }
+
+    #[test]
+    fn rejects_dice_face_out_of_range() {
+        // to_digit(10)은 0/7/8/9도 자릿수로 받아들이지만, 주사위 눈은 1~6뿐이다
+        for bad in ["09999", "71234", "12348", "12340"] {
+            let err = parse_command(&format!("ROLL {bad} 12345")).unwrap_err();
+            assert!(matches!(err, ParseError::InvalidDice { command: "ROLL", field: "dice_a", .. }));
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_length_dice_token() {
+        let err = parse_command("ROLL 1234 12345").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidDice { command: "ROLL", field: "dice_a", .. }));
+    }
+
+    #[test]
+    fn parses_get() {
+        let command = parse_command("GET A B 3000").unwrap();
+        match command {
+            Command::Get { my_group, opp_group, opp_amount } => {
+                assert_eq!(my_group, 'A');
+                assert_eq!(opp_group, 'B');
+                assert_eq!(opp_amount, 3000);
+            }
+            _ => panic!("expected Command::Get"),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_group_letter() {
+        let err = parse_command("GET C B 3000").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidGroup { command: "GET", field: "my_group", .. }));
+    }
+
+    #[test]
+    fn parses_score() {
+        assert!(matches!(parse_command("SCORE").unwrap(), Command::Score));
+    }
+
+    #[test]
+    fn parses_set_with_valid_rule() {
+        let command = parse_command("SET YACHT 55555").unwrap();
+        match command {
+            Command::Set { rule, dice } => {
+                assert_eq!(rule, DiceRule::Yacht);
+                assert_eq!(dice, [5, 5, 5, 5, 5]);
+            }
+            _ => panic!("expected Command::Set"),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_rule_name() {
+        let err = parse_command("SET NOPE 12345").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidRule { command: "SET", field: "rule", .. }));
+    }
+
+    #[test]
+    fn parses_finish() {
+        assert!(matches!(parse_command("FINISH").unwrap(), Command::Finish));
+    }
+
+    #[test]
+    fn reports_unknown_command_instead_of_panicking() {
+        let err = parse_command("WHATEVER").unwrap_err();
+        assert!(matches!(err, ParseError::UnknownCommand(name) if name == "WHATEVER"));
+    }
+
+    #[test]
+    fn reports_missing_token_instead_of_panicking() {
+        let err = parse_command("GET A").unwrap_err();
+        assert!(matches!(err, ParseError::MissingToken { command: "GET", field: "opp_group" }));
+    }
 }